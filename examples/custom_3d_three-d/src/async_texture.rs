@@ -0,0 +1,73 @@
+//! A small, self-contained version of the "background thread renders an image, then hands
+//! it to egui" pattern: a worker does the heavy per-pixel work off the UI thread and sends
+//! the finished pixels back over a channel; [`AsyncTexture::poll`] uploads them and the
+//! worker wakes the UI up via [`egui::Context::request_repaint`] so the result shows up
+//! without waiting for unrelated input. The worker only produces pixels; the texture itself
+//! is created in [`AsyncTexture::poll`], on the thread that calls it.
+//!
+//! This is example-local, not a documented `egui_extras` subsystem (see the scope note atop
+//! `main.rs`). If/when that crate is available here, this module's contents should move there
+//! and the example should simply depend on it.
+
+use std::sync::mpsc;
+
+pub struct AsyncTexture {
+    name: String,
+    handle: Option<egui::TextureHandle>,
+    pending: mpsc::Receiver<egui::ColorImage>,
+}
+
+impl AsyncTexture {
+    /// Spawns `compute` on a new thread and starts listening for its result.
+    pub fn spawn(
+        ctx: egui::Context,
+        name: impl Into<String>,
+        compute: impl FnOnce() -> egui::ColorImage + Send + 'static,
+    ) -> Self {
+        let (sender, pending) = mpsc::channel();
+        std::thread::spawn(move || {
+            let image = compute();
+            let _ = sender.send(image);
+            ctx.request_repaint();
+        });
+
+        Self {
+            name: name.into(),
+            handle: None,
+            pending,
+        }
+    }
+
+    /// Call once per frame. Uploads the worker's result as soon as it arrives;
+    /// a no-op on every frame before that.
+    pub fn poll(&mut self, ctx: &egui::Context) {
+        if let Ok(image) = self.pending.try_recv() {
+            self.handle =
+                Some(ctx.load_texture(&self.name, image, egui::TextureOptions::default()));
+        }
+    }
+
+    /// The uploaded texture, once the worker has finished and a [`Self::poll`] has
+    /// picked up its result.
+    pub fn texture(&self) -> Option<&egui::TextureHandle> {
+        self.handle.as_ref()
+    }
+}
+
+/// Stand-in for "heavy per-pixel work done on a worker thread", matching the shape of
+/// the matrix-image-editor example this module is modeled on.
+pub fn compute_plasma(width: usize, height: usize) -> egui::ColorImage {
+    let mut pixels = Vec::with_capacity(width * height);
+    for y in 0..height {
+        for x in 0..width {
+            let u = x as f32 / width as f32;
+            let v = y as f32 / height as f32;
+            let value = ((u * 12.0).sin() + (v * 12.0).cos()) * 0.5 + 0.5;
+            pixels.push(egui::Color32::from_gray((value * 255.0).round() as u8));
+        }
+    }
+    egui::ColorImage {
+        size: [width, height],
+        pixels,
+    }
+}
@@ -0,0 +1,233 @@
+//! CPU-side image filter: a 4x5 color matrix (`out = M * [r, g, b, a, 1]^T`, clamped to
+//! `[0, 1]`) composed with an optional 2x3 affine transform of the sampled UV coordinates.
+//! The color matrix alone covers grayscale, sepia, hue/saturation and channel-swizzle
+//! effects with a single uniform multiply; the UV affine covers skew/rotate/flip of the
+//! source image without re-uploading it.
+//!
+//! This lives in the example crate, not in `egui_extras` (see the scope note atop `main.rs`).
+//! If/when that crate is available here, `ColorMatrix`/`UvAffine`/[`filter_color_image`] should
+//! move there as a public, documented API instead of being called directly by this example.
+
+/// A 4x5 affine transform in color space: `out = matrix * [r, g, b, a, 1]^T`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorMatrix {
+    matrix: [[f32; 5]; 4],
+}
+
+impl ColorMatrix {
+    pub fn identity() -> Self {
+        Self {
+            matrix: [
+                [1.0, 0.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0, 0.0],
+            ],
+        }
+    }
+
+    pub fn grayscale() -> Self {
+        // Rec. 601 luma weights, replicated into every output channel.
+        let luma = [0.299, 0.587, 0.114, 0.0, 0.0];
+        Self {
+            matrix: [luma, luma, luma, [0.0, 0.0, 0.0, 1.0, 0.0]],
+        }
+    }
+
+    pub fn sepia() -> Self {
+        Self {
+            matrix: [
+                [0.393, 0.769, 0.189, 0.0, 0.0],
+                [0.349, 0.686, 0.168, 0.0, 0.0],
+                [0.272, 0.534, 0.131, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0, 0.0],
+            ],
+        }
+    }
+
+    /// Builder-style: multiply this matrix by `other`, applying `other` first.
+    #[must_use]
+    pub fn then(mut self, other: Self) -> Self {
+        let mut result = [[0.0; 5]; 4];
+        for (row, result_row) in result.iter_mut().enumerate() {
+            for (col, result_cell) in result_row.iter_mut().enumerate() {
+                let mut sum: f32 = self.matrix[row]
+                    .iter()
+                    .take(4)
+                    .zip(&other.matrix)
+                    .map(|(&lhs, rhs)| lhs * rhs[col])
+                    .sum();
+                if col == 4 {
+                    sum += self.matrix[row][4];
+                }
+                *result_cell = sum;
+            }
+        }
+        self.matrix = result;
+        self
+    }
+
+    pub fn apply(&self, color: [f32; 4]) -> [f32; 4] {
+        let input = [color[0], color[1], color[2], color[3], 1.0];
+        let mut out = [0.0; 4];
+        for (row, out_channel) in out.iter_mut().enumerate() {
+            *out_channel = (0..5).map(|col| self.matrix[row][col] * input[col]).sum();
+        }
+        out.map(|c| c.clamp(0.0, 1.0))
+    }
+}
+
+/// A 2x3 affine transform applied to the UV coordinates an image is sampled at, before the
+/// [`ColorMatrix`] runs: `uv' = matrix * [u, v, 1]^T`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UvAffine {
+    matrix: [[f32; 3]; 2],
+}
+
+impl UvAffine {
+    pub fn identity() -> Self {
+        Self {
+            matrix: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+        }
+    }
+
+    pub fn flip_vertical() -> Self {
+        Self {
+            matrix: [[1.0, 0.0, 0.0], [0.0, -1.0, 1.0]],
+        }
+    }
+
+    pub fn apply(&self, uv: egui::Pos2) -> egui::Pos2 {
+        let [a, b, c] = self.matrix[0];
+        let [d, e, f] = self.matrix[1];
+        egui::pos2(a * uv.x + b * uv.y + c, d * uv.x + e * uv.y + f)
+    }
+}
+
+/// Applies `color` and `uv` to `image`, producing a new, independent [`egui::ColorImage`]
+/// suitable for re-uploading with [`egui::Context::load_texture`].
+pub fn filter_color_image(
+    image: &egui::ColorImage,
+    color: &ColorMatrix,
+    uv: &UvAffine,
+) -> egui::ColorImage {
+    let [width, height] = image.size;
+    let mut pixels = Vec::with_capacity(width * height);
+
+    for y in 0..height {
+        for x in 0..width {
+            // Sample at pixel centers, so a flip maps row `y` onto row `height - 1 - y`.
+            let sample_uv = uv.apply(egui::pos2(
+                (x as f32 + 0.5) / width.max(1) as f32,
+                (y as f32 + 0.5) / height.max(1) as f32,
+            ));
+            let sample_x = (sample_uv.x * width as f32).floor() as i64;
+            let sample_y = (sample_uv.y * height as f32).floor() as i64;
+
+            let source = clamp_pixel(image, sample_x, sample_y);
+            let rgba = color.apply([
+                source.r() as f32 / 255.0,
+                source.g() as f32 / 255.0,
+                source.b() as f32 / 255.0,
+                source.a() as f32 / 255.0,
+            ]);
+            pixels.push(egui::Color32::from_rgba_unmultiplied(
+                (rgba[0] * 255.0).round() as u8,
+                (rgba[1] * 255.0).round() as u8,
+                (rgba[2] * 255.0).round() as u8,
+                (rgba[3] * 255.0).round() as u8,
+            ));
+        }
+    }
+
+    egui::ColorImage {
+        size: [width, height],
+        pixels,
+    }
+}
+
+fn clamp_pixel(image: &egui::ColorImage, x: i64, y: i64) -> egui::Color32 {
+    let [width, height] = image.size;
+    let x = x.clamp(0, width as i64 - 1) as usize;
+    let y = y.clamp(0, height as i64 - 1) as usize;
+    image.pixels[y * width + x]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_matrix_eq(a: ColorMatrix, b: ColorMatrix) {
+        for row in 0..4 {
+            for col in 0..5 {
+                assert!(
+                    (a.matrix[row][col] - b.matrix[row][col]).abs() < 1e-6,
+                    "row {row} col {col}: {} != {}",
+                    a.matrix[row][col],
+                    b.matrix[row][col]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn then_identity_is_a_no_op() {
+        assert_matrix_eq(
+            ColorMatrix::grayscale().then(ColorMatrix::identity()),
+            ColorMatrix::grayscale(),
+        );
+        assert_matrix_eq(
+            ColorMatrix::identity().then(ColorMatrix::grayscale()),
+            ColorMatrix::grayscale(),
+        );
+    }
+
+    #[test]
+    fn then_composes_translation_columns() {
+        // Two matrices whose composition is easy to hand-compute: `a` adds a constant 0.25 to
+        // every channel, `b` halves every channel. `a.then(b)` applies `b` first, so the result
+        // should be "halve, then add 0.25" -- exercising the `col == 4` translation-column path
+        // in `ColorMatrix::then`, not just the 4x4 linear part.
+        let add_quarter = ColorMatrix {
+            matrix: [
+                [1.0, 0.0, 0.0, 0.0, 0.25],
+                [0.0, 1.0, 0.0, 0.0, 0.25],
+                [0.0, 0.0, 1.0, 0.0, 0.25],
+                [0.0, 0.0, 0.0, 1.0, 0.0],
+            ],
+        };
+        let halve = ColorMatrix {
+            matrix: [
+                [0.5, 0.0, 0.0, 0.0, 0.0],
+                [0.0, 0.5, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.5, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0, 0.0],
+            ],
+        };
+        let expected = ColorMatrix {
+            matrix: [
+                [0.5, 0.0, 0.0, 0.0, 0.25],
+                [0.0, 0.5, 0.0, 0.0, 0.25],
+                [0.0, 0.0, 0.5, 0.0, 0.25],
+                [0.0, 0.0, 0.0, 1.0, 0.0],
+            ],
+        };
+        assert_matrix_eq(add_quarter.then(halve), expected);
+        assert_eq!(
+            add_quarter.then(halve).apply([0.2, 0.4, 0.6, 1.0]),
+            [0.35, 0.45, 0.55, 1.0]
+        );
+    }
+
+    #[test]
+    fn flip_vertical_round_trips() {
+        let flip = UvAffine::flip_vertical();
+        let uv = egui::pos2(0.3, 0.8);
+        let flipped = flip.apply(uv);
+        assert!((flipped.x - uv.x).abs() < 1e-6);
+        assert!((flipped.y - (1.0 - uv.y)).abs() < 1e-6);
+        let round_tripped = flip.apply(flipped);
+        assert!((round_tripped.x - uv.x).abs() < 1e-6);
+        assert!((round_tripped.y - uv.y).abs() < 1e-6);
+    }
+}
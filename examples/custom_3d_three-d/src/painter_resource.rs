@@ -0,0 +1,109 @@
+//! A render-thread-local resource slot, keyed to the lifetime of whoever owns it, for storing
+//! a `!Send` value (like `three_d::Context`) that a `Send + Sync` `egui_glow::CallbackFn`
+//! needs to reach into every frame.
+//!
+//! This is the example-local approximation of the ticket's ask for first-class, painter-keyed
+//! storage on `egui_glow::Painter` itself (a `HashMap<TypeId, Box<dyn Any>>` the painter would
+//! own and free when it's dropped); the storage can't actually live on `Painter` here (see the
+//! scope note atop `main.rs`). What it *can* do, without touching `egui_glow` at all, is avoid
+//! the two problems the ticket called out on the thread_local and the `Arc<Mutex<_>>` gist
+//! workaround:
+//! - unlike `thread_local! { RefCell<Option<T>> }`, each [`PainterResource<T>`] has its own slot
+//!   in the map, keyed to its own lifetime, so it doesn't leak across unrelated app instances
+//!   and is freed deterministically when dropped;
+//! - unlike `Arc<Mutex<T>>`, there's no lock: the map is a plain `RefCell` on a `thread_local!`,
+//!   so [`PainterResource::with`] is only sound to call from the thread painting actually
+//!   happens on -- the same assumption both of the rejected designs it replaces already made.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+thread_local! {
+    static SLOTS: RefCell<HashMap<u64, Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+static NEXT_KEY: AtomicU64 = AtomicU64::new(0);
+
+/// A typed handle to a lazily-created value in the render thread's resource map.
+///
+/// `PhantomData<fn() -> T>` (rather than `PhantomData<T>`) keeps `PainterResource<T>` itself
+/// `Send + Sync` regardless of whether `T` is, since this type never actually stores or moves
+/// a `T` across threads -- it only ever reaches `T` through [`Self::with`], called from the
+/// single thread that owns the map entry.
+pub struct PainterResource<T: 'static> {
+    key: u64,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: 'static> PainterResource<T> {
+    pub fn new() -> Self {
+        Self {
+            key: NEXT_KEY.fetch_add(1, Ordering::Relaxed),
+            _marker: PhantomData,
+        }
+    }
+
+    /// A copyable reference to this slot, for moving into the `'static` closure an
+    /// `egui_glow::CallbackFn` requires. `PainterResource` itself is not `Clone`: it owns the
+    /// slot's lifetime, so cloning it would let one clone's `Drop` free the slot out from under
+    /// the other. The key has no such ownership, so it can be copied freely.
+    pub fn key(&self) -> PainterResourceKey<T> {
+        PainterResourceKey {
+            key: self.key,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Runs `f` against the stored value, creating it via `init` on first access. No locking:
+    /// only sound to call from the thread that paint callbacks for this resource run on.
+    pub fn with<R>(&self, init: impl FnOnce() -> T, f: impl FnOnce(&T) -> R) -> R {
+        self.key().with(init, f)
+    }
+}
+
+impl<T: 'static> Default for PainterResource<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: 'static> Drop for PainterResource<T> {
+    fn drop(&mut self) {
+        SLOTS.with(|slots| {
+            slots.borrow_mut().remove(&self.key);
+        });
+    }
+}
+
+/// A `Copy`, `Send + Sync` handle to a [`PainterResource`]'s slot, without owning its lifetime.
+pub struct PainterResourceKey<T: 'static> {
+    key: u64,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: 'static> Clone for PainterResourceKey<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for PainterResourceKey<T> {}
+
+impl<T: 'static> PainterResourceKey<T> {
+    /// Runs `f` against the stored value, creating it via `init` on first access. No locking:
+    /// only sound to call from the thread that paint callbacks for this resource run on.
+    pub fn with<R>(&self, init: impl FnOnce() -> T, f: impl FnOnce(&T) -> R) -> R {
+        SLOTS.with(|slots| {
+            let mut slots = slots.borrow_mut();
+            let value = slots
+                .entry(self.key)
+                .or_insert_with(|| Box::new(init()) as Box<dyn Any>);
+            f(value
+                .downcast_ref::<T>()
+                .expect("PainterResource type mismatch"))
+        })
+    }
+}
@@ -0,0 +1,309 @@
+#![cfg_attr(not(debug_assertions), windows_subsystem = "windows")] // hide console window on Windows in release
+
+// This example shows eframe apps configured with `eframe::Renderer::Wgpu` how to register a
+// `PaintCallback`, the way `examples/custom_3d_three-d` shows for `eframe::Renderer::Glow`.
+// Unlike that example, `MyApp` here doesn't assume one backend up front: `CreationContext`
+// exposes `gl`/`wgpu_render_state` as `Option`s because the configured renderer can still fail
+// to initialize and fall back to the other one, so `custom_painting` dispatches on whichever
+// one is actually `Some` and builds the matching `egui_glow`/`egui_wgpu` callback from the same
+// call site, via the `Custom3d` enum below.
+
+use eframe::egui;
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {
+    let options = eframe::NativeOptions {
+        initial_window_size: Some(egui::vec2(550.0, 610.0)),
+        multisampling: 1,
+        renderer: eframe::Renderer::Wgpu,
+        depth_buffer: 0,
+        ..Default::default()
+    };
+    eframe::run_native(
+        "Custom 3D painting in eframe using wgpu",
+        options,
+        Box::new(|cc| Box::new(MyApp::new(cc))),
+    );
+}
+
+pub struct MyApp {
+    angle: f32,
+    custom3d: Custom3d,
+}
+
+impl MyApp {
+    pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
+        let custom3d = if let Some(gl) = &cc.gl {
+            Custom3d::Glow(GlowTriangle::new(gl))
+        } else if let Some(wgpu_render_state) = &cc.wgpu_render_state {
+            // Register our custom paint callback's resources so they outlive a single frame
+            // and are available the next time the callback is invoked.
+            wgpu_render_state
+                .renderer
+                .write()
+                .paint_callback_resources
+                .insert(WgpuTriangle::new(
+                    &wgpu_render_state.device,
+                    wgpu_render_state.target_format,
+                ));
+            Custom3d::Wgpu
+        } else {
+            panic!("eframe must be configured with either the glow or the wgpu renderer");
+        };
+
+        Self {
+            angle: 0.2,
+            custom3d,
+        }
+    }
+}
+
+impl eframe::App for MyApp {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::widgets::global_dark_light_mode_buttons(ui);
+
+            ui.horizontal(|ui| {
+                ui.spacing_mut().item_spacing.x = 0.0;
+                ui.label("The triangle is being painted using ");
+                ui.hyperlink_to("wgpu", "https://wgpu.rs/");
+                ui.label(" (falling back to glow if wgpu failed to initialize).");
+            });
+
+            egui::ScrollArea::both().show(ui, |ui| {
+                egui::Frame::canvas(ui.style()).show(ui, |ui| {
+                    self.custom_painting(ui);
+                });
+                ui.label("Drag to rotate!");
+            });
+        });
+    }
+
+    fn on_exit(&mut self, gl: Option<&glow::Context>) {
+        if let (Custom3d::Glow(glow_triangle), Some(gl)) = (&self.custom3d, gl) {
+            glow_triangle.destroy(gl);
+        }
+    }
+}
+
+impl MyApp {
+    fn custom_painting(&mut self, ui: &mut egui::Ui) {
+        let (rect, response) =
+            ui.allocate_exact_size(egui::Vec2::splat(512.0), egui::Sense::drag());
+
+        self.angle += response.drag_delta().x * 0.01;
+
+        // Clone locals so we can move them into the paint callback:
+        let angle = self.angle;
+
+        // This is the one call site an app needs, regardless of which renderer eframe ended up
+        // using: `Custom3d` already knows which backend it was built for, so it hands back the
+        // matching `egui::PaintCallback` either way.
+        let callback = match &self.custom3d {
+            Custom3d::Glow(glow_triangle) => {
+                let glow_triangle = *glow_triangle;
+                egui::PaintCallback {
+                    rect,
+                    callback: std::sync::Arc::new(egui_glow::CallbackFn::new(
+                        move |_info, painter| {
+                            glow_triangle.paint(painter.gl(), angle);
+                        },
+                    )),
+                }
+            }
+            Custom3d::Wgpu => egui::PaintCallback {
+                rect,
+                callback: std::sync::Arc::new(egui_wgpu::CallbackFn::new().paint(
+                    move |_info, render_pass, paint_callback_resources| {
+                        let triangle: &WgpuTriangle = paint_callback_resources.get().unwrap();
+                        triangle.paint(render_pass, angle);
+                    },
+                )),
+            },
+        };
+        ui.painter().add(callback);
+    }
+}
+
+/// Whichever backend's custom-paint resources `MyApp` ended up creating, keyed to the renderer
+/// `CreationContext` reported as actually active. `egui_glow::CallbackFn` and
+/// `egui_wgpu::CallbackFn` stay distinct types (they're built for, and invoked by, two different
+/// painters), so this only has to carry enough to pick the right one in [`MyApp::custom_painting`].
+enum Custom3d {
+    /// `glow::Program`/`glow::VertexArray` are plain GL object ids, so unlike
+    /// `three_d::Context` they're `Copy` and can be moved into the `Send + Sync` closure
+    /// `egui_glow::CallbackFn` requires with no special storage needed.
+    Glow(GlowTriangle),
+    /// The wgpu render pipeline lives in `paint_callback_resources` instead (see
+    /// [`MyApp::new`]), so there's nothing to store here.
+    Wgpu,
+}
+
+/// A hand-rolled glow triangle, analogous to [`WgpuTriangle`] but for the
+/// `eframe::Renderer::Glow` fallback path.
+#[derive(Clone, Copy)]
+struct GlowTriangle {
+    program: glow::Program,
+    vertex_array: glow::VertexArray,
+}
+
+impl GlowTriangle {
+    fn new(gl: &glow::Context) -> Self {
+        use glow::HasContext as _;
+
+        let shader_version = if cfg!(target_arch = "wasm32") {
+            "#version 300 es"
+        } else {
+            "#version 330"
+        };
+
+        unsafe {
+            let program = gl.create_program().expect("Cannot create program");
+
+            let vertex_shader_source = r#"
+                const vec2 verts[3] = vec2[3](
+                    vec2(0.0, 1.0),
+                    vec2(-1.0, -1.0),
+                    vec2(1.0, -1.0)
+                );
+                out vec2 v_pos;
+                uniform float u_angle;
+                void main() {
+                    v_pos = verts[gl_VertexID];
+                    float c = cos(u_angle);
+                    float s = sin(u_angle);
+                    gl_Position = vec4(mat2(c, -s, s, c) * v_pos, 0.0, 1.0);
+                }
+            "#;
+            let fragment_shader_source = r#"
+                precision mediump float;
+                in vec2 v_pos;
+                out vec4 out_color;
+                void main() {
+                    out_color = vec4(v_pos * 0.5 + 0.5, 1.0, 1.0);
+                }
+            "#;
+
+            let shader_sources = [
+                (glow::VERTEX_SHADER, vertex_shader_source),
+                (glow::FRAGMENT_SHADER, fragment_shader_source),
+            ];
+
+            let shaders: Vec<_> = shader_sources
+                .iter()
+                .map(|(shader_type, shader_source)| {
+                    let shader = gl
+                        .create_shader(*shader_type)
+                        .expect("Cannot create shader");
+                    gl.shader_source(shader, &format!("{shader_version}\n{shader_source}"));
+                    gl.compile_shader(shader);
+                    assert!(
+                        gl.get_shader_compile_status(shader),
+                        "{}",
+                        gl.get_shader_info_log(shader)
+                    );
+                    gl.attach_shader(program, shader);
+                    shader
+                })
+                .collect();
+
+            gl.link_program(program);
+            assert!(
+                gl.get_program_link_status(program),
+                "{}",
+                gl.get_program_info_log(program)
+            );
+
+            for shader in shaders {
+                gl.detach_shader(program, shader);
+                gl.delete_shader(shader);
+            }
+
+            let vertex_array = gl
+                .create_vertex_array()
+                .expect("Cannot create vertex array");
+
+            Self {
+                program,
+                vertex_array,
+            }
+        }
+    }
+
+    fn paint(&self, gl: &glow::Context, angle: f32) {
+        use glow::HasContext as _;
+        unsafe {
+            gl.use_program(Some(self.program));
+            gl.uniform_1_f32(
+                gl.get_uniform_location(self.program, "u_angle").as_ref(),
+                angle,
+            );
+            gl.bind_vertex_array(Some(self.vertex_array));
+            gl.draw_arrays(glow::TRIANGLES, 0, 3);
+        }
+    }
+
+    fn destroy(&self, gl: &glow::Context) {
+        use glow::HasContext as _;
+        unsafe {
+            gl.delete_program(self.program);
+            gl.delete_vertex_array(self.vertex_array);
+        }
+    }
+}
+
+/// Everything needed to render our triangle with wgpu, registered once into
+/// `paint_callback_resources` in [`MyApp::new`] and looked up again each time the
+/// [`egui::PaintCallback`] is painted.
+struct WgpuTriangle {
+    pipeline: wgpu::RenderPipeline,
+}
+
+impl WgpuTriangle {
+    fn new(device: &wgpu::Device, target_format: wgpu::TextureFormat) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("custom3d"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("triangle.wgsl").into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("custom3d"),
+            bind_group_layouts: &[],
+            push_constant_ranges: &[wgpu::PushConstantRange {
+                stages: wgpu::ShaderStages::VERTEX,
+                range: 0..std::mem::size_of::<f32>() as u32,
+            }],
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("custom3d"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(target_format.into())],
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        Self { pipeline }
+    }
+
+    fn paint<'rp>(&'rp self, render_pass: &mut wgpu::RenderPass<'rp>, angle: f32) {
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_push_constants(
+            wgpu::ShaderStages::VERTEX,
+            0,
+            bytemuck::cast_slice(&[angle]),
+        );
+        render_pass.draw(0..3, 0..1);
+    }
+}